@@ -16,8 +16,19 @@
 // under the License.
 
 use crate::cmd::{get_cmd_output, run_command};
+use regex::Regex;
+use std::fmt;
+use std::io::{BufRead, BufReader};
+use std::net::TcpStream;
 use std::process::Command;
-use std::net::{SocketAddr, is_unspecified};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, OnceLock, Once};
+use std::time::{Duration, Instant};
+
+/// Default amount of time a [`WaitStrategy`] will poll before giving up.
+pub const DEFAULT_WAIT_TIMEOUT: Duration = Duration::from_secs(60);
+/// Default delay between two successive readiness checks of a [`WaitStrategy`].
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(500);
 
 #[derive(Debug)]
 enum EngineProvider {
@@ -25,6 +36,299 @@ enum EngineProvider {
     Podman
 }
 
+/// Identifies a live `DockerCompose` project so that the signal handler can tear it
+/// down without needing access to the (non-reentrant) `DockerCompose` value itself.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct RegisteredProject {
+    project_name: String,
+    docker_compose_dir: String,
+}
+
+static LIVE_PROJECTS: OnceLock<Mutex<Vec<RegisteredProject>>> = OnceLock::new();
+static SIGNAL_HANDLER_INSTALLED: Once = Once::new();
+static SHUTDOWN_REQUESTED: AtomicBool = AtomicBool::new(false);
+/// How often the teardown watcher thread polls `SHUTDOWN_REQUESTED` for a pending signal.
+const SHUTDOWN_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+fn live_projects() -> &'static Mutex<Vec<RegisteredProject>> {
+    LIVE_PROJECTS.get_or_init(|| Mutex::new(Vec::new()))
+}
+
+/// Installs, at most once per process, a handler that tears down every currently
+/// registered `DockerCompose` project on `SIGINT`/`SIGTERM`. Without this, a test run
+/// killed by Ctrl-C in CI (or a timeout) skips `Drop` entirely and leaks containers.
+///
+/// The raw signal handler only sets `SHUTDOWN_REQUESTED` — that's the only thing it's
+/// safe to do in a signal-handler context. A dedicated watcher thread polls that flag and
+/// does the actual teardown (locking `live_projects()`, spawning `docker compose down`),
+/// since running that directly in the handler could deadlock if the interrupted thread
+/// already held the same (non-reentrant) `Mutex`, e.g. mid-`push` in `DockerCompose::new`
+/// or mid-`retain` in `Drop`.
+fn ensure_signal_handler_installed() {
+    SIGNAL_HANDLER_INSTALLED.call_once(|| {
+        for signal in [signal_hook::consts::SIGINT, signal_hook::consts::SIGTERM] {
+            unsafe {
+                signal_hook_registry::register(signal, || {
+                    SHUTDOWN_REQUESTED.store(true, Ordering::SeqCst);
+                })
+                .expect("Unable to install signal handler for docker compose teardown");
+            }
+        }
+
+        std::thread::spawn(|| {
+            loop {
+                if SHUTDOWN_REQUESTED.load(Ordering::SeqCst) {
+                    for project in live_projects().lock().unwrap().drain(..) {
+                        let mut cmd = Command::new("docker");
+                        cmd.current_dir(&project.docker_compose_dir);
+                        cmd.args(vec![
+                            "compose",
+                            "-p",
+                            project.project_name.as_str(),
+                            "down",
+                            "-v",
+                            "--remove-orphans",
+                        ]);
+                        let _ = cmd.status();
+                    }
+                    std::process::exit(1);
+                }
+                std::thread::sleep(SHUTDOWN_POLL_INTERVAL);
+            }
+        });
+    });
+}
+
+/// Error returned when a [`WaitStrategy`] does not observe readiness before its timeout elapses.
+#[derive(Debug)]
+pub struct WaitTimeoutError {
+    service: String,
+    strategy: String,
+    timeout: Duration,
+    logs: Option<String>,
+}
+
+impl fmt::Display for WaitTimeoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "service `{}` did not become ready via {} within {:?}",
+            self.service, self.strategy, self.timeout
+        )?;
+
+        if let Some(logs) = &self.logs {
+            write!(f, "\n--- recent logs for `{}` ---\n{logs}", self.service)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for WaitTimeoutError {}
+
+/// Best-effort capture of a service's recent logs, attached to a [`WaitTimeoutError`] so a
+/// timed-out wait reports *why* the dependency never became ready instead of just that it
+/// gave up.
+fn dump_logs_on_failure(compose: &DockerCompose, service: &str) -> Option<String> {
+    let container = compose.service_container_opt(service, 0)?;
+
+    let mut cmd = Command::new("docker");
+    cmd.arg("logs").arg("--tail").arg("50").arg(&container.name);
+
+    let output = cmd.output().ok()?;
+    Some(format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    ))
+}
+
+/// A pluggable readiness check for a single service started by [`DockerCompose`].
+///
+/// Unlike `docker compose up --wait`, which only understands Docker's own
+/// healthchecks, a `WaitStrategy` lets callers define what "ready" means for
+/// the specific dependency being waited on.
+pub trait WaitStrategy: fmt::Debug {
+    /// Poll `service` until it is considered ready, or return an error once `self`'s
+    /// timeout elapses.
+    fn wait(&self, compose: &DockerCompose, service: &str) -> Result<(), WaitTimeoutError>;
+}
+
+/// Waits until a line in the container's logs matches a regular expression.
+#[derive(Debug)]
+pub struct LogMatches {
+    pattern: Regex,
+    timeout: Duration,
+    poll_interval: Duration,
+}
+
+impl LogMatches {
+    pub fn new(pattern: impl AsRef<str>) -> Self {
+        Self {
+            pattern: Regex::new(pattern.as_ref()).expect("invalid regex pattern"),
+            timeout: DEFAULT_WAIT_TIMEOUT,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+        }
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+}
+
+impl WaitStrategy for LogMatches {
+    fn wait(&self, compose: &DockerCompose, service: &str) -> Result<(), WaitTimeoutError> {
+        let deadline = Instant::now() + self.timeout;
+
+        loop {
+            if let Some(container) = compose.service_container_opt(service, 0) {
+                let mut cmd = Command::new("docker");
+                cmd.arg("logs").arg(&container.name);
+
+                if let Ok(output) = cmd.output() {
+                    let stdout = String::from_utf8_lossy(&output.stdout);
+                    let stderr = String::from_utf8_lossy(&output.stderr);
+                    if stdout
+                        .lines()
+                        .chain(stderr.lines())
+                        .any(|line| self.pattern.is_match(line))
+                    {
+                        return Ok(());
+                    }
+                }
+            }
+
+            if Instant::now() >= deadline {
+                return Err(WaitTimeoutError {
+                    service: service.to_string(),
+                    strategy: format!("LogMatches({})", self.pattern),
+                    timeout: self.timeout,
+                    logs: dump_logs_on_failure(compose, service),
+                });
+            }
+
+            std::thread::sleep(self.poll_interval);
+        }
+    }
+}
+
+/// Waits until a TCP connection can be established to the mapped socket of a container port.
+#[derive(Debug)]
+pub struct PortOpen {
+    port: u16,
+    timeout: Duration,
+    poll_interval: Duration,
+}
+
+impl PortOpen {
+    pub fn new(port: u16) -> Self {
+        Self {
+            port,
+            timeout: DEFAULT_WAIT_TIMEOUT,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+        }
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+}
+
+impl WaitStrategy for PortOpen {
+    fn wait(&self, compose: &DockerCompose, service: &str) -> Result<(), WaitTimeoutError> {
+        let deadline = Instant::now() + self.timeout;
+
+        loop {
+            if let Some((host, port)) = compose.container_socket_opt(service, self.port, 0) {
+                if TcpStream::connect((host.as_str(), port)).is_ok() {
+                    return Ok(());
+                }
+            }
+
+            if Instant::now() >= deadline {
+                return Err(WaitTimeoutError {
+                    service: service.to_string(),
+                    strategy: format!("PortOpen({})", self.port),
+                    timeout: self.timeout,
+                    logs: dump_logs_on_failure(compose, service),
+                });
+            }
+
+            std::thread::sleep(self.poll_interval);
+        }
+    }
+}
+
+/// Waits until an HTTP GET against a container port returns a 2xx status.
+#[derive(Debug)]
+pub struct HttpOk {
+    port: u16,
+    path: String,
+    timeout: Duration,
+    poll_interval: Duration,
+}
+
+impl HttpOk {
+    pub fn new(port: u16, path: impl ToString) -> Self {
+        Self {
+            port,
+            path: path.to_string(),
+            timeout: DEFAULT_WAIT_TIMEOUT,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+        }
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+}
+
+impl WaitStrategy for HttpOk {
+    fn wait(&self, compose: &DockerCompose, service: &str) -> Result<(), WaitTimeoutError> {
+        let deadline = Instant::now() + self.timeout;
+
+        loop {
+            if let Some((host, port)) = compose.container_socket_opt(service, self.port, 0) {
+                let url = format!("http://{host}:{port}{}", self.path);
+                if let Ok(response) = ureq::get(&url).call() {
+                    if response.status() >= 200 && response.status() < 300 {
+                        return Ok(());
+                    }
+                }
+            }
+
+            if Instant::now() >= deadline {
+                return Err(WaitTimeoutError {
+                    service: service.to_string(),
+                    strategy: format!("HttpOk({})", self.path),
+                    timeout: self.timeout,
+                    logs: dump_logs_on_failure(compose, service),
+                });
+            }
+
+            std::thread::sleep(self.poll_interval);
+        }
+    }
+}
+
 /// A utility to manage the lifecycle of `docker compose`.
 ///
 /// It will start `docker compose` when calling the `run` method and will be stopped via [`Drop`].
@@ -51,9 +355,21 @@ fn get_engine_provider() -> EngineProvider {
 
 impl DockerCompose {
     pub fn new(project_name: impl ToString, docker_compose_dir: impl ToString) -> Self {
+        let project_name = project_name.to_string();
+        let docker_compose_dir = docker_compose_dir.to_string();
+
+        ensure_signal_handler_installed();
+        live_projects()
+            .lock()
+            .unwrap()
+            .push(RegisteredProject {
+                project_name: project_name.clone(),
+                docker_compose_dir: docker_compose_dir.clone(),
+            });
+
         Self {
-            project_name: project_name.to_string(),
-            docker_compose_dir: docker_compose_dir.to_string(),
+            project_name,
+            docker_compose_dir,
             engine_provider: get_engine_provider()
         }
     }
@@ -74,6 +390,34 @@ impl DockerCompose {
     }
 
     pub fn run(&self) {
+        self.up(true)
+    }
+
+    /// Like [`Self::run`], but additionally calls [`Self::verify_running`] afterwards so a
+    /// container that started, passed its healthcheck, then crashed is reported immediately
+    /// instead of surfacing downstream as a mysterious connection refused.
+    pub fn run_verified(&self) {
+        self.up(true);
+        if let Err(err) = self.verify_running() {
+            panic!("{err}");
+        }
+    }
+
+    /// Starts the compose project without relying on Docker's own `--wait` healthchecks,
+    /// then blocks on `waits` so that each listed service is brought up according to
+    /// whatever readiness means for it (e.g. a REST catalog serving requests, a metastore
+    /// accepting connections), rather than Docker's blanket notion of "started".
+    pub fn run_with_waits(&self, waits: &[(&str, Box<dyn WaitStrategy>)]) {
+        self.up(false);
+
+        for (service, strategy) in waits {
+            if let Err(err) = strategy.wait(self, service) {
+                panic!("{err}");
+            }
+        }
+    }
+
+    fn up(&self, wait: bool) {
         let mut cmd = Command::new("docker");
         cmd.current_dir(&self.docker_compose_dir);
 
@@ -85,11 +429,12 @@ impl DockerCompose {
             self.project_name.as_str(),
             "up",
             "-d",
-            "--wait",
-            "--timeout",
-            "1200000",
         ]);
 
+        if wait {
+            cmd.args(vec!["--wait", "--timeout", "1200000"]);
+        }
+
         run_command(
             cmd,
             format!(
@@ -99,56 +444,558 @@ impl DockerCompose {
         )
     }
 
+    /// Lists every container currently making up this compose project, discovered via
+    /// `docker compose ps` rather than assumed from the `{project}-{service}-1` naming
+    /// convention (which breaks for scaled services and custom `container_name`s).
+    pub fn services(&self) -> Vec<ServiceContainer> {
+        let mut cmd = Command::new("docker");
+        cmd.current_dir(&self.docker_compose_dir);
+        cmd.args(vec![
+            "compose",
+            "-p",
+            self.project_name.as_str(),
+            "ps",
+            "--all",
+            "--format",
+            "json",
+        ]);
+
+        let output = get_cmd_output(
+            cmd,
+            format!("List services for project {}", self.project_name),
+        );
+
+        parse_service_containers(&output)
+    }
+
+    fn service_container(&self, service_name: &str, replica: usize) -> ServiceContainer {
+        self.service_container_opt(service_name, replica)
+            .unwrap_or_else(|| {
+                panic!(
+                    "No running container for service `{service_name}` in project `{}`",
+                    self.project_name
+                )
+            })
+    }
+
+    fn service_container_opt(&self, service_name: &str, replica: usize) -> Option<ServiceContainer> {
+        let mut matching: Vec<ServiceContainer> = self
+            .services()
+            .into_iter()
+            .filter(|container| container.service == service_name)
+            .collect();
+
+        if replica >= matching.len() {
+            return None;
+        }
+
+        Some(matching.remove(replica))
+    }
+
+    /// Captures a service's `docker logs` output (stdout and stderr combined).
+    ///
+    /// Panics if `opts.follow` is set, since `docker logs --follow` never exits on its own;
+    /// use [`Self::logs_stream`] to follow a service's logs.
+    pub fn logs(&self, service_name: impl AsRef<str>, opts: &LogsOptions) -> String {
+        assert!(
+            !opts.follow,
+            "logs() cannot be used with `follow` set, since `docker logs --follow` never \
+             exits on its own; use logs_stream() instead"
+        );
+
+        let container = self.service_container(service_name.as_ref(), 0);
+        let output = self
+            .logs_command(&container.name, opts)
+            .output()
+            .unwrap_or_else(|err| panic!("Unable to run docker logs for {}: {err}", container.name));
+
+        format!(
+            "{}{}",
+            String::from_utf8_lossy(&output.stdout),
+            String::from_utf8_lossy(&output.stderr)
+        )
+    }
+
+    /// Like [`Self::logs`] but returns an iterator of lines as they're produced, instead of
+    /// buffering the whole capture. Intended for `opts.follow == true`.
+    ///
+    /// Returns a [`LogsStream`] rather than a bare iterator so that `docker logs --follow`
+    /// (and its reader threads) are killed if the caller stops iterating early — otherwise
+    /// the subprocess and a thread blocked reading its stderr would leak for the remaining
+    /// life of the container.
+    pub fn logs_stream(&self, service_name: impl AsRef<str>, opts: &LogsOptions) -> LogsStream {
+        let container = self.service_container(service_name.as_ref(), 0);
+        let mut cmd = self.logs_command(&container.name, opts);
+        cmd.stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped());
+
+        let mut child = cmd
+            .spawn()
+            .unwrap_or_else(|err| panic!("Unable to spawn docker logs for {}: {err}", container.name));
+        let stdout = child.stdout.take().expect("stdout was piped");
+        let stderr = child.stderr.take().expect("stderr was piped");
+
+        // `docker logs` writes to stderr as readily as stdout; if only stdout is drained,
+        // the stderr pipe buffer fills, the child blocks on write, and this iterator stalls
+        // silently. Drain both on background threads and merge them into one channel. Killing
+        // `child` (see `LogsStream`'s `Drop`) closes both pipes, which unblocks these threads.
+        let (tx, rx) = std::sync::mpsc::channel();
+
+        let stdout_tx = tx.clone();
+        std::thread::spawn(move || {
+            for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+                if stdout_tx.send(line).is_err() {
+                    return;
+                }
+            }
+        });
+
+        std::thread::spawn(move || {
+            for line in BufReader::new(stderr).lines().map_while(Result::ok) {
+                if tx.send(line).is_err() {
+                    return;
+                }
+            }
+        });
+
+        LogsStream { child, rx }
+    }
+
+    fn logs_command(&self, container_name: &str, opts: &LogsOptions) -> Command {
+        let mut cmd = Command::new("docker");
+        cmd.arg("logs");
+
+        if let Some(tail) = &opts.tail {
+            cmd.arg("--tail").arg(tail);
+        }
+        if let Some(since) = &opts.since {
+            cmd.arg("--since").arg(since);
+        }
+        if opts.follow {
+            cmd.arg("--follow");
+        }
+
+        cmd.arg(container_name);
+        cmd
+    }
+
     pub fn get_container_ip(&self, service_name: impl AsRef<str>) -> String {
-        let container_name = format!("{}-{}-1", self.project_name, service_name.as_ref());
+        self.get_container_ip_nth(service_name, 0)
+    }
+
+    /// Like [`Self::get_container_ip`] but addresses a specific replica of a scaled service.
+    pub fn get_container_ip_nth(&self, service_name: impl AsRef<str>, replica: usize) -> String {
+        let container = self.service_container(service_name.as_ref(), replica);
+
+        self.container_ip(&container.name)
+            .unwrap_or_else(|| panic!("Unable to get container ip of {}", container.name))
+    }
+
+    fn container_ip(&self, container_name: &str) -> Option<String> {
         let mut cmd = Command::new("docker");
         cmd.arg("inspect")
             .arg("-f")
             .arg("{{range.NetworkSettings.Networks}}{{.IPAddress}}{{end}}")
-            .arg(&container_name);
+            .arg(container_name);
 
-        get_cmd_output(cmd, format!("Get container ip of {container_name}"))
-            .trim()
-            .to_string()
+        let output = cmd.output().ok()?;
+        if !output.status.success() {
+            return None;
+        }
+
+        Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
     }
 
     pub fn get_mapped_container_socket(&self, service_name: impl AsRef<str>, unmapped_port: u16) -> (String, u16) {
-        let container_name = format!("{}-{}-1", self.project_name, service_name.as_ref());
-        let mut cmd = Command::new("docker");
-        cmd.arg("port")
-            .arg(&container_name)
-            .arg(unmapped_port.to_string());
+        self.get_mapped_container_socket_nth(service_name, unmapped_port, 0)
+    }
 
-        let mapped_socket: SocketAddr = get_cmd_output(cmd, format!("Get port mapping for {container_name}"))
-            .trim()
-            .to_string()
-            .parse()
-            .expect("Unable to parse socket address");
+    /// Like [`Self::get_mapped_container_socket`] but addresses a specific replica of a
+    /// scaled service.
+    pub fn get_mapped_container_socket_nth(
+        &self,
+        service_name: impl AsRef<str>,
+        unmapped_port: u16,
+        replica: usize,
+    ) -> (String, u16) {
+        let container = self.service_container(service_name.as_ref(), replica);
+
+        Self::mapped_container_socket(&container, unmapped_port).unwrap_or_else(|| {
+            panic!(
+                "No published port mapping for {unmapped_port} on {}",
+                container.name
+            )
+        })
+    }
+
+    fn mapped_container_socket(container: &ServiceContainer, unmapped_port: u16) -> Option<(String, u16)> {
+        let publisher = container
+            .publishers
+            .iter()
+            .find(|publisher| publisher.target_port == unmapped_port)?;
 
-        if mapped_socket.ip().is_unspecified() {
-            (String::from("127.0.0.1"), mapped_socket.port())
+        let host = if publisher.url.is_empty() {
+            String::from("127.0.0.1")
         } else {
-            (mapped_socket.ip().to_string(), mapped_socket.port())
-        }
+            match publisher.url.parse::<std::net::IpAddr>() {
+                Ok(ip) if ip.is_unspecified() => String::from("127.0.0.1"),
+                _ => publisher.url.clone(),
+            }
+        };
+
+        Some((host, publisher.published_port))
     }
 
     pub fn get_container_socket(&self, service_name: impl AsRef<str>, unmapped_port: u16) -> (String, u16) {
+        self.get_container_socket_nth(service_name, unmapped_port, 0)
+    }
+
+    /// Like [`Self::get_container_socket`] but addresses a specific replica of a scaled service.
+    pub fn get_container_socket_nth(
+        &self,
+        service_name: impl AsRef<str>,
+        unmapped_port: u16,
+        replica: usize,
+    ) -> (String, u16) {
         match self.engine_provider {
             // docker containers always get an addressable IP, so no portforwarding
             EngineProvider::Docker => {
-                (self.get_container_ip(service_name), unmapped_port)
+                (self.get_container_ip_nth(service_name, replica), unmapped_port)
             }
             // podman rootless containers don't get an IP by default.
             // Instead, they share host IP and forward container ports to the host.
             EngineProvider::Podman => {
-                self.get_mapped_container_socket(service_name, unmapped_port)
+                self.get_mapped_container_socket_nth(service_name, unmapped_port, replica)
+            }
+        }
+    }
+
+    /// Non-panicking counterpart to [`Self::get_container_socket`], used by [`WaitStrategy`]
+    /// implementations so a container that isn't discoverable (or listed) *yet* just causes
+    /// another poll iteration instead of an immediate hard panic.
+    fn container_socket_opt(&self, service_name: &str, unmapped_port: u16, replica: usize) -> Option<(String, u16)> {
+        let container = self.service_container_opt(service_name, replica)?;
+
+        match self.engine_provider {
+            EngineProvider::Docker => {
+                let ip = self.container_ip(&container.name)?;
+                Some((ip, unmapped_port))
+            }
+            EngineProvider::Podman => Self::mapped_container_socket(&container, unmapped_port),
+        }
+    }
+
+    /// Runs `cmd` inside the running `service` container via `docker exec`.
+    ///
+    /// Useful for seeding a catalog, creating buckets, or otherwise driving a dependency
+    /// from the test after it has started. Panics if the service has no running container.
+    pub fn exec(&self, service_name: impl AsRef<str>, cmd: &[&str]) -> ExecOutput {
+        self.exec_nth(service_name, 0, cmd)
+    }
+
+    /// Like [`Self::exec`] but addresses a specific replica of a scaled service.
+    pub fn exec_nth(&self, service_name: impl AsRef<str>, replica: usize, cmd: &[&str]) -> ExecOutput {
+        let service_name = service_name.as_ref();
+        let container = self.service_container(service_name, replica);
+
+        if container.state != "running" {
+            panic!(
+                "Service `{service_name}` container `{}` is not running (state: {})",
+                container.name, container.state
+            );
+        }
+
+        // Target `container.name` directly (as every other accessor in this file does)
+        // rather than `docker compose exec --index=N`, whose index is assigned by compose
+        // independently of the order `services()` returned `container` in — the two could
+        // disagree and silently run against a different replica than the one just checked.
+        let mut command = Command::new("docker");
+        command.args(vec!["exec", &container.name]);
+        command.args(cmd);
+
+        let output = command
+            .output()
+            .unwrap_or_else(|err| panic!("Unable to run `docker exec` for {}: {err}", container.name));
+
+        ExecOutput {
+            stdout: String::from_utf8_lossy(&output.stdout).to_string(),
+            stderr: String::from_utf8_lossy(&output.stderr).to_string(),
+            status: output.status.code().unwrap_or(-1),
+        }
+    }
+
+    /// Like [`Self::exec`] but returns an error instead of a zero/nonzero `status` when
+    /// the command exits non-zero.
+    pub fn exec_checked(&self, service_name: impl AsRef<str>, cmd: &[&str]) -> Result<ExecOutput, ExecError> {
+        self.exec_checked_nth(service_name, 0, cmd)
+    }
+
+    /// Like [`Self::exec_checked`] but addresses a specific replica of a scaled service.
+    pub fn exec_checked_nth(
+        &self,
+        service_name: impl AsRef<str>,
+        replica: usize,
+        cmd: &[&str],
+    ) -> Result<ExecOutput, ExecError> {
+        let service_name = service_name.as_ref();
+        let output = self.exec_nth(service_name, replica, cmd);
+
+        if output.status == 0 {
+            Ok(output)
+        } else {
+            Err(ExecError {
+                service: service_name.to_string(),
+                cmd: cmd.iter().map(|arg| arg.to_string()).collect(),
+                output,
+            })
+        }
+    }
+
+    /// Checks every container in the project and reports any that started then silently
+    /// crashed (`.State.Status` of `exited`/`dead`), rather than letting them surface
+    /// downstream as a confusing connection refused.
+    pub fn verify_running(&self) -> Result<(), ContainersNotRunningError> {
+        let crashed: Vec<CrashedContainer> = self
+            .services()
+            .into_iter()
+            .filter_map(|container| {
+                let (status, exit_code) = self.inspect_state(&container.name);
+                if status == "exited" || status == "dead" {
+                    let logs = dump_logs_on_failure(self, &container.service);
+                    Some(CrashedContainer {
+                        service: container.service,
+                        container_name: container.name,
+                        status,
+                        exit_code,
+                        logs,
+                    })
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        if crashed.is_empty() {
+            Ok(())
+        } else {
+            Err(ContainersNotRunningError { crashed })
+        }
+    }
+
+    fn inspect_state(&self, container_name: &str) -> (String, i64) {
+        let mut cmd = Command::new("docker");
+        cmd.arg("inspect")
+            .arg("-f")
+            .arg("{{.State.Status}} {{.State.ExitCode}}")
+            .arg(container_name);
+
+        let output = get_cmd_output(cmd, format!("Get container state of {container_name}"));
+        let mut fields = output.trim().splitn(2, ' ');
+        let status = fields.next().unwrap_or_default().to_string();
+        let exit_code = fields.next().and_then(|code| code.parse().ok()).unwrap_or(-1);
+
+        (status, exit_code)
+    }
+}
+
+/// A container found by [`DockerCompose::verify_running`] that exited or died after starting.
+#[derive(Debug)]
+pub struct CrashedContainer {
+    pub service: String,
+    pub container_name: String,
+    pub status: String,
+    pub exit_code: i64,
+    pub logs: Option<String>,
+}
+
+/// Error returned by [`DockerCompose::verify_running`] enumerating every crashed container.
+#[derive(Debug)]
+pub struct ContainersNotRunningError {
+    pub crashed: Vec<CrashedContainer>,
+}
+
+impl fmt::Display for ContainersNotRunningError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} container(s) are not running:", self.crashed.len())?;
+        for container in &self.crashed {
+            writeln!(
+                f,
+                "- service `{}` ({}): status={} exit_code={}",
+                container.service, container.container_name, container.status, container.exit_code
+            )?;
+            if let Some(logs) = &container.logs {
+                writeln!(f, "  --- recent logs ---\n{logs}")?;
             }
         }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ContainersNotRunningError {}
+
+/// The captured result of [`DockerCompose::exec`].
+#[derive(Debug, Clone)]
+pub struct ExecOutput {
+    pub stdout: String,
+    pub stderr: String,
+    pub status: i32,
+}
+
+/// Error returned by [`DockerCompose::exec_checked`] when the command exits non-zero.
+#[derive(Debug)]
+pub struct ExecError {
+    pub service: String,
+    pub cmd: Vec<String>,
+    pub output: ExecOutput,
+}
+
+impl fmt::Display for ExecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "command `{}` in service `{}` exited with status {}\n--- stdout ---\n{}\n--- stderr ---\n{}",
+            self.cmd.join(" "),
+            self.service,
+            self.output.status,
+            self.output.stdout,
+            self.output.stderr
+        )
+    }
+}
+
+impl std::error::Error for ExecError {}
+
+/// A container's published port mapping, as reported by `docker compose ps --format json`.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct Publisher {
+    #[serde(rename = "URL", default)]
+    pub url: String,
+    #[serde(rename = "TargetPort", default)]
+    pub target_port: u16,
+    #[serde(rename = "PublishedPort", default)]
+    pub published_port: u16,
+    #[serde(rename = "Protocol", default)]
+    pub protocol: String,
+}
+
+/// A single container belonging to a compose project, as reported by
+/// `docker compose ps --format json`.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ServiceContainer {
+    #[serde(rename = "Service")]
+    pub service: String,
+    #[serde(rename = "Name")]
+    pub name: String,
+    #[serde(rename = "State")]
+    pub state: String,
+    #[serde(rename = "Health", default)]
+    pub health: String,
+    #[serde(rename = "Publishers", default)]
+    pub publishers: Vec<Publisher>,
+}
+
+/// Parses the output of `docker compose ps --format json`, which some Docker Compose
+/// versions emit as a single JSON array and others as newline-delimited JSON objects.
+fn parse_service_containers(output: &str) -> Vec<ServiceContainer> {
+    let trimmed = output.trim();
+    if trimmed.is_empty() {
+        return Vec::new();
+    }
+
+    if let Ok(containers) = serde_json::from_str::<Vec<ServiceContainer>>(trimmed) {
+        return containers;
+    }
+
+    trimmed
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            serde_json::from_str(line)
+                .unwrap_or_else(|err| panic!("Unable to parse `docker compose ps` output: {err}\n{line}"))
+        })
+        .collect()
+}
+
+/// Options for [`DockerCompose::logs`]/[`DockerCompose::logs_stream`], modeled on shiplift's
+/// `LogsOptions`.
+#[derive(Debug, Clone, Default)]
+pub struct LogsOptions {
+    tail: Option<String>,
+    since: Option<String>,
+    follow: bool,
+}
+
+impl LogsOptions {
+    pub fn builder() -> LogsOptionsBuilder {
+        LogsOptionsBuilder::default()
+    }
+}
+
+/// Builder for [`LogsOptions`].
+#[derive(Debug, Clone, Default)]
+pub struct LogsOptionsBuilder {
+    opts: LogsOptions,
+}
+
+impl LogsOptionsBuilder {
+    /// Number of lines to show from the end of the logs (e.g. `"100"`), or `"all"`.
+    pub fn tail(mut self, tail: impl ToString) -> Self {
+        self.opts.tail = Some(tail.to_string());
+        self
+    }
+
+    /// Only show logs since this timestamp (RFC3339) or relative duration (e.g. `"10m"`).
+    pub fn since(mut self, since: impl ToString) -> Self {
+        self.opts.since = Some(since.to_string());
+        self
+    }
+
+    /// Keep streaming logs as they're produced instead of returning once they're exhausted.
+    pub fn follow(mut self, follow: bool) -> Self {
+        self.opts.follow = follow;
+        self
+    }
+
+    pub fn build(self) -> LogsOptions {
+        self.opts
+    }
+}
+
+/// A live `docker logs` stream returned by [`DockerCompose::logs_stream`].
+///
+/// Owns the underlying `docker logs` [`Child`](std::process::Child) so that dropping a
+/// `LogsStream` before it's exhausted — the normal way to stop watching a `follow`-mode
+/// stream once some condition is seen — kills the subprocess instead of leaking it (and
+/// the reader threads blocked on its pipes) for the remaining life of the container.
+pub struct LogsStream {
+    child: std::process::Child,
+    rx: std::sync::mpsc::Receiver<String>,
+}
+
+impl Iterator for LogsStream {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        self.rx.recv().ok()
+    }
+}
+
+impl Drop for LogsStream {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
     }
 }
 
 impl Drop for DockerCompose {
     fn drop(&mut self) {
+        // Deregister first so the signal handler never races a `compose down` we're
+        // about to run ourselves.
+        live_projects().lock().unwrap().retain(|project| {
+            !(project.project_name == self.project_name
+                && project.docker_compose_dir == self.docker_compose_dir)
+        });
+
         let mut cmd = Command::new("docker");
         cmd.current_dir(&self.docker_compose_dir);
 
@@ -170,3 +1017,45 @@ impl Drop for DockerCompose {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_service_containers_handles_json_array() {
+        let output = r#"[
+            {"Service": "rest", "Name": "proj-rest-1", "State": "running", "Health": "healthy", "Publishers": [{"URL": "0.0.0.0", "TargetPort": 8181, "PublishedPort": 53123, "Protocol": "tcp"}]},
+            {"Service": "minio", "Name": "proj-minio-1", "State": "running", "Health": "", "Publishers": []}
+        ]"#;
+
+        let containers = parse_service_containers(output);
+
+        assert_eq!(containers.len(), 2);
+        assert_eq!(containers[0].service, "rest");
+        assert_eq!(containers[0].name, "proj-rest-1");
+        assert_eq!(containers[0].publishers[0].published_port, 53123);
+        assert_eq!(containers[1].service, "minio");
+        assert!(containers[1].publishers.is_empty());
+    }
+
+    #[test]
+    fn parse_service_containers_handles_ndjson() {
+        let output = "{\"Service\": \"rest\", \"Name\": \"proj-rest-1\", \"State\": \"exited\", \"Health\": \"\", \"Publishers\": []}\n\
+                      {\"Service\": \"minio\", \"Name\": \"proj-minio-1\", \"State\": \"running\", \"Health\": \"healthy\", \"Publishers\": []}\n";
+
+        let containers = parse_service_containers(output);
+
+        assert_eq!(containers.len(), 2);
+        assert_eq!(containers[0].service, "rest");
+        assert_eq!(containers[0].state, "exited");
+        assert_eq!(containers[1].service, "minio");
+        assert_eq!(containers[1].state, "running");
+    }
+
+    #[test]
+    fn parse_service_containers_handles_empty_output() {
+        assert!(parse_service_containers("").is_empty());
+        assert!(parse_service_containers("   \n").is_empty());
+    }
+}